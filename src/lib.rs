@@ -151,6 +151,57 @@ where
   pub fn length(&self) -> T {
     self.square_length().sqrt()
   }
+
+  /// Returns a unit-length copy of this quaternion.
+  pub fn normalize(&self) -> Quaternion<T> {
+    self.scale(T::one() / self.length())
+  }
+
+  /// Scales this quaternion to unit length in place.
+  pub fn normalize_mut(&mut self) {
+    self.scale_mut(T::one() / self.length());
+  }
+
+  /// Returns true if this quaternion's length is within `tol` of 1.
+  pub fn is_normalized(&self, tol: T) -> bool {
+    (self.length() - T::one()).abs() < tol
+  }
+
+  /// Spherically interpolates between two quaternions by `t` (0.0 to 1.0), taking the
+  /// shorter arc between them.
+  ///
+  /// Falls back to [`Quaternion::nlerp`] when the quaternions are nearly parallel, since
+  /// `sin(theta)` would otherwise be too close to zero to divide by.
+  pub fn slerp(&self, other: &Quaternion<T>, t: T) -> Quaternion<T> {
+    let one = T::one();
+    let a = self.scale(one / self.length());
+    let mut b = other.scale(one / other.length());
+
+    let mut cos_theta = a.dot(&b);
+    if cos_theta < T::zero() {
+      b = -b;
+      cos_theta = -cos_theta;
+    }
+
+    if cos_theta > one - T::epsilon() {
+      return a.nlerp(&b, t);
+    }
+
+    let theta = cos_theta.acos();
+    let sin_theta = theta.sin();
+
+    a.scale((((one - t) * theta).sin()) / sin_theta) + b.scale(((t * theta).sin()) / sin_theta)
+  }
+
+  /// Normalized linear interpolation between two quaternions by `t` (0.0 to 1.0).
+  ///
+  /// Cheaper than [`Quaternion::slerp`] but does not interpolate at a constant angular
+  /// velocity.
+  pub fn nlerp(&self, other: &Quaternion<T>, t: T) -> Quaternion<T> {
+    let one = T::one();
+    let result = self.scale(one - t) + other.scale(t);
+    result.scale(one / result.length())
+  }
 }
 
 impl<T> Default for Quaternion<T>
@@ -307,7 +358,7 @@ where
 {
   /// Construct a quaternion representing the given euler angle rotations (in radians)
   #[inline(always)]
-  pub fn from_euler_angles(x: T, y: T, z: T) -> Quaternion<T> {
+  pub fn from_euler_angles(x: T, y: T, z: T) -> UnitQuaternion<T> {
     let two: T = T::one() + T::one();
 
     let half_x = x / two;
@@ -322,11 +373,339 @@ where
     let sin_y_2 = half_y.sin();
     let sin_z_2 = half_z.sin();
 
-    Quaternion {
+    UnitQuaternion::new(Quaternion {
       w: cos_x_2 * cos_y_2 * cos_z_2 + sin_x_2 * sin_y_2 * sin_z_2,
-      x: sin_x_2 * cos_y_2 * cos_z_2 + cos_x_2 * sin_y_2 * sin_z_2,
+      x: sin_x_2 * cos_y_2 * cos_z_2 - cos_x_2 * sin_y_2 * sin_z_2,
       y: cos_x_2 * sin_y_2 * cos_z_2 + sin_x_2 * cos_y_2 * sin_z_2,
-      z: cos_x_2 * cos_y_2 * sin_z_2 + sin_x_2 * sin_y_2 * cos_z_2,
+      z: cos_x_2 * cos_y_2 * sin_z_2 - sin_x_2 * sin_y_2 * cos_z_2,
+    })
+  }
+}
+
+/// A quaternion known to have unit length, i.e. a pure rotation.
+///
+/// Rotation-producing constructors such as [`Quaternion::from_euler_angles`] return a
+/// `UnitQuaternion` so the unit invariant can't silently drift through ordinary
+/// arithmetic. It dereferences to [`Quaternion`], so all of the general read-only API
+/// (`dot`, `length`, `rotate_vector`, ...) is available directly.
+#[derive(Debug, Copy, Clone)]
+pub struct UnitQuaternion<T: Float>(Quaternion<T>);
+
+impl<T> UnitQuaternion<T>
+where
+  T: Float,
+{
+  /// Normalizes `q` and wraps it, guaranteeing the result has unit length.
+  pub fn new(q: Quaternion<T>) -> Self {
+    UnitQuaternion(q.normalize())
+  }
+
+  /// Returns the inverse rotation. Since the quaternion is already unit length this is
+  /// just the conjugate, skipping the `square_length` division `Quaternion::inverse` needs.
+  pub fn inverse(&self) -> Self {
+    UnitQuaternion(self.0.conjugate())
+  }
+}
+
+impl<T> std::ops::Deref for UnitQuaternion<T>
+where
+  T: Float,
+{
+  type Target = Quaternion<T>;
+
+  fn deref(&self) -> &Quaternion<T> {
+    &self.0
+  }
+}
+
+impl<T> Quaternion<T>
+where
+  T: Float,
+{
+  /// Converts the (normalized) quaternion into a 3x3 rotation matrix, in row-major order.
+  pub fn to_rotation_matrix3(&self) -> [[T; 3]; 3] {
+    let one = T::one();
+    let two = one + one;
+    let q = self.scale(one / self.length());
+
+    let xx = q.x * q.x;
+    let yy = q.y * q.y;
+    let zz = q.z * q.z;
+    let xy = q.x * q.y;
+    let xz = q.x * q.z;
+    let yz = q.y * q.z;
+    let wx = q.w * q.x;
+    let wy = q.w * q.y;
+    let wz = q.w * q.z;
+
+    [
+      [one - two * (yy + zz), two * (xy - wz), two * (xz + wy)],
+      [two * (xy + wz), one - two * (xx + zz), two * (yz - wx)],
+      [two * (xz - wy), two * (yz + wx), one - two * (xx + yy)],
+    ]
+  }
+
+  /// Converts the (normalized) quaternion into a 4x4 homogeneous rotation matrix, in
+  /// row-major order.
+  pub fn to_rotation_matrix4(&self) -> [[T; 4]; 4] {
+    let zero = T::zero();
+    let one = T::one();
+    let m3 = self.to_rotation_matrix3();
+
+    [
+      [m3[0][0], m3[0][1], m3[0][2], zero],
+      [m3[1][0], m3[1][1], m3[1][2], zero],
+      [m3[2][0], m3[2][1], m3[2][2], zero],
+      [zero, zero, zero, one],
+    ]
+  }
+
+  /// Builds a quaternion from a 3x3 rotation matrix (row-major), using the
+  /// numerically-stable trace method to avoid catastrophic cancellation near the poles.
+  pub fn from_rotation_matrix3(m: &[[T; 3]; 3]) -> Quaternion<T> {
+    let zero = T::zero();
+    let one = T::one();
+    let two = one + one;
+
+    let trace = m[0][0] + m[1][1] + m[2][2];
+
+    if trace > zero {
+      let s = (T::from(0.5).unwrap()) / (trace + one).sqrt();
+      Quaternion {
+        w: T::from(0.25).unwrap() / s,
+        x: (m[2][1] - m[1][2]) * s,
+        y: (m[0][2] - m[2][0]) * s,
+        z: (m[1][0] - m[0][1]) * s,
+      }
+    } else if m[0][0] > m[1][1] && m[0][0] > m[2][2] {
+      let s = two * (one + m[0][0] - m[1][1] - m[2][2]).sqrt();
+      Quaternion {
+        w: (m[2][1] - m[1][2]) / s,
+        x: T::from(0.25).unwrap() * s,
+        y: (m[0][1] + m[1][0]) / s,
+        z: (m[0][2] + m[2][0]) / s,
+      }
+    } else if m[1][1] > m[2][2] {
+      let s = two * (one + m[1][1] - m[0][0] - m[2][2]).sqrt();
+      Quaternion {
+        w: (m[0][2] - m[2][0]) / s,
+        x: (m[0][1] + m[1][0]) / s,
+        y: T::from(0.25).unwrap() * s,
+        z: (m[1][2] + m[2][1]) / s,
+      }
+    } else {
+      let s = two * (one + m[2][2] - m[0][0] - m[1][1]).sqrt();
+      Quaternion {
+        w: (m[1][0] - m[0][1]) / s,
+        x: (m[0][2] + m[2][0]) / s,
+        y: (m[1][2] + m[2][1]) / s,
+        z: T::from(0.25).unwrap() * s,
+      }
+    }
+  }
+}
+
+impl<T> Quaternion<T>
+where
+  T: Float,
+{
+  /// Rotates a 3D vector by this (normalized) quaternion.
+  ///
+  /// Uses the optimized form `v' = v + 2w(q.xyz x v) + 2(q.xyz x (q.xyz x v))`, which
+  /// avoids the two full quaternion multiplies of the naive `q * (0, v) * q.conjugate()`
+  /// sandwich.
+  pub fn rotate_vector(&self, v: [T; 3]) -> [T; 3] {
+    let two = T::one() + T::one();
+    let q = self.scale(T::one() / self.length());
+    let qv = [q.x, q.y, q.z];
+
+    let t = cross(qv, v).map(|c| c * two);
+    let qv_cross_t = cross(qv, t);
+
+    [
+      v[0] + q.w * t[0] + qv_cross_t[0],
+      v[1] + q.w * t[1] + qv_cross_t[1],
+      v[2] + q.w * t[2] + qv_cross_t[2],
+    ]
+  }
+}
+
+fn cross<T: Float>(a: [T; 3], b: [T; 3]) -> [T; 3] {
+  [
+    a[1] * b[2] - a[2] * b[1],
+    a[2] * b[0] - a[0] * b[2],
+    a[0] * b[1] - a[1] * b[0],
+  ]
+}
+
+fn clamp<T: Float>(v: T, lo: T, hi: T) -> T {
+  if v < lo {
+    lo
+  } else if v > hi {
+    hi
+  } else {
+    v
+  }
+}
+
+impl<T> Quaternion<T>
+where
+  T: Float,
+{
+  /// Constructs a rotation of `angle` radians about `axis` (which need not be normalized).
+  pub fn from_axis_angle(axis: [T; 3], angle: T) -> UnitQuaternion<T> {
+    let two = T::one() + T::one();
+    let axis_len = (axis[0] * axis[0] + axis[1] * axis[1] + axis[2] * axis[2]).sqrt();
+    let axis = [axis[0] / axis_len, axis[1] / axis_len, axis[2] / axis_len];
+
+    let half = angle / two;
+    let sin_half = half.sin();
+
+    UnitQuaternion::new(Quaternion {
+      w: half.cos(),
+      x: axis[0] * sin_half,
+      y: axis[1] * sin_half,
+      z: axis[2] * sin_half,
+    })
+  }
+
+  /// Extracts the `(axis, angle)` pair this (normalized) quaternion rotates by.
+  ///
+  /// When the rotation angle is close to zero the axis is underdetermined; an arbitrary
+  /// axis of `[1, 0, 0]` is returned in that case.
+  pub fn to_axis_angle(&self) -> ([T; 3], T) {
+    let two = T::one() + T::one();
+    let q = self.normalize();
+    let angle = two * clamp(q.w, -T::one(), T::one()).acos();
+
+    let sin_half = (T::one() - q.w * q.w).sqrt();
+    if sin_half < T::epsilon() {
+      ([T::one(), T::zero(), T::zero()], angle)
+    } else {
+      ([q.x / sin_half, q.y / sin_half, q.z / sin_half], angle)
+    }
+  }
+
+  /// Recovers the `(x, y, z)` euler angle rotations (in radians) that
+  /// [`Quaternion::from_euler_angles`] would have produced, clamping the pitch term to
+  /// guard against gimbal lock.
+  pub fn to_euler_angles(&self) -> (T, T, T) {
+    let one = T::one();
+    let two = one + one;
+    let q = self.normalize();
+
+    let sinr_cosp = two * (q.w * q.x + q.y * q.z);
+    let cosr_cosp = one - two * (q.x * q.x + q.y * q.y);
+    let roll = sinr_cosp.atan2(cosr_cosp);
+
+    let sinp = two * (q.w * q.y - q.z * q.x);
+    let pitch = clamp(sinp, -one, one).asin();
+
+    let siny_cosp = two * (q.w * q.z + q.x * q.y);
+    let cosy_cosp = one - two * (q.y * q.y + q.z * q.z);
+    let yaw = siny_cosp.atan2(cosy_cosp);
+
+    (roll, pitch, yaw)
+  }
+}
+
+impl<T> Quaternion<T>
+where
+  T: Float,
+{
+  /// Computes the quaternion exponential `e^self`.
+  pub fn exp(&self) -> Quaternion<T> {
+    let v_norm = (self.x * self.x + self.y * self.y + self.z * self.z).sqrt();
+    let e_w = self.w.exp();
+
+    if v_norm < T::epsilon() {
+      Quaternion {
+        w: e_w * v_norm.cos(),
+        x: T::zero(),
+        y: T::zero(),
+        z: T::zero(),
+      }
+    } else {
+      let coeff = e_w * v_norm.sin() / v_norm;
+      Quaternion {
+        w: e_w * v_norm.cos(),
+        x: self.x * coeff,
+        y: self.y * coeff,
+        z: self.z * coeff,
+      }
     }
   }
+
+  /// Computes the quaternion logarithm `ln(self)`.
+  pub fn ln(&self) -> Quaternion<T> {
+    let length = self.length();
+    let v_norm = (self.x * self.x + self.y * self.y + self.z * self.z).sqrt();
+
+    if v_norm < T::epsilon() {
+      Quaternion {
+        w: length.ln(),
+        x: T::zero(),
+        y: T::zero(),
+        z: T::zero(),
+      }
+    } else {
+      let theta = (self.w / length).acos();
+      let coeff = theta / v_norm;
+      Quaternion {
+        w: length.ln(),
+        x: self.x * coeff,
+        y: self.y * coeff,
+        z: self.z * coeff,
+      }
+    }
+  }
+
+  /// Raises this quaternion to the fractional power `t`, e.g. `pow(0.5)` is the "half
+  /// rotation". Defined as `exp(t * ln(self))`.
+  pub fn pow(&self, t: T) -> Quaternion<T> {
+    self.ln().scale(t).exp()
+  }
+}
+
+impl<T> Quaternion<T>
+where
+  T: Float,
+{
+  /// Constructs the shortest-arc rotation that takes the direction `from` onto `to`
+  /// (neither need be normalized).
+  pub fn rotation_between(from: [T; 3], to: [T; 3]) -> Quaternion<T> {
+    let one = T::one();
+    let epsilon = T::epsilon();
+
+    let from_len = (from[0] * from[0] + from[1] * from[1] + from[2] * from[2]).sqrt();
+    let to_len = (to[0] * to[0] + to[1] * to[1] + to[2] * to[2]).sqrt();
+    let from = [from[0] / from_len, from[1] / from_len, from[2] / from_len];
+    let to = [to[0] / to_len, to[1] / to_len, to[2] / to_len];
+
+    let d = from[0] * to[0] + from[1] * to[1] + from[2] * to[2];
+
+    if d > one - epsilon {
+      return Quaternion::id();
+    }
+
+    if d < -one + epsilon {
+      // `from` and `to` point in opposite directions: pick any axis orthogonal to `from`.
+      let mut axis = cross(from, [one, T::zero(), T::zero()]);
+      let axis_len = (axis[0] * axis[0] + axis[1] * axis[1] + axis[2] * axis[2]).sqrt();
+      if axis_len < epsilon {
+        axis = cross(from, [T::zero(), one, T::zero()]);
+      }
+      let pi = (-one).acos();
+      return *Quaternion::from_axis_angle(axis, pi);
+    }
+
+    let xyz = cross(from, to);
+    let q = Quaternion {
+      w: one + d,
+      x: xyz[0],
+      y: xyz[1],
+      z: xyz[2],
+    };
+    q.normalize()
+  }
 }
@@ -1,6 +1,6 @@
 extern crate quaternions;
 
-use quaternions::{q, qi, Quaternion};
+use quaternions::{q, qi, Quaternion, UnitQuaternion};
 
 /// Tests
 
@@ -55,7 +55,7 @@ fn test_arithmetic_mut() {
 
 #[test]
 fn test_euler_angle() {
-  let q: Quaternion<f32> = Quaternion::from_euler_angles(PI, PI, PI);
+  let q: UnitQuaternion<f32> = Quaternion::from_euler_angles(PI, PI, PI);
   // Should be a unit quaternion
   assert!((q.square_length() - 1.0).abs() < EPSILON);
 }
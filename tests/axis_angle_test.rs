@@ -0,0 +1,53 @@
+extern crate quaternions;
+
+use quaternions::Quaternion;
+
+/// Fudge factor for float equality checks
+static EPSILON: f32 = 1e-5;
+static PI: f32 = std::f32::consts::PI;
+
+#[test]
+fn test_axis_angle_round_trip() {
+  let axis = [0.0_f32, 0.0, 1.0];
+  let angle = PI / 2.0;
+
+  let q: Quaternion<f32> = *Quaternion::from_axis_angle(axis, angle);
+  let (out_axis, out_angle) = q.to_axis_angle();
+
+  assert!((out_angle - angle).abs() < EPSILON);
+  assert!((out_axis[0] - axis[0]).abs() < EPSILON);
+  assert!((out_axis[1] - axis[1]).abs() < EPSILON);
+  assert!((out_axis[2] - axis[2]).abs() < EPSILON);
+}
+
+#[test]
+fn test_axis_angle_near_zero_returns_arbitrary_axis() {
+  let q: Quaternion<f32> = Quaternion::id();
+  let (axis, angle) = q.to_axis_angle();
+
+  assert!(angle.abs() < EPSILON);
+  assert_eq!(axis, [1.0, 0.0, 0.0]);
+}
+
+#[test]
+fn test_euler_angle_round_trip() {
+  let x = 0.3_f32;
+  let y = 0.4_f32;
+  let z = 0.5_f32;
+
+  let q: Quaternion<f32> = *Quaternion::from_euler_angles(x, y, z);
+  let (roll, pitch, yaw) = q.to_euler_angles();
+
+  assert!((roll - x).abs() < EPSILON, "roll: {} vs {}", roll, x);
+  assert!((pitch - y).abs() < EPSILON, "pitch: {} vs {}", pitch, y);
+  assert!((yaw - z).abs() < EPSILON, "yaw: {} vs {}", yaw, z);
+}
+
+#[test]
+fn test_euler_angle_round_trip_near_gimbal_lock() {
+  // Pitch of +pi/2 sits exactly at the gimbal lock singularity the `asin` clamp guards.
+  let q: Quaternion<f32> = *Quaternion::from_euler_angles(0.1, PI / 2.0, 0.2);
+  let (_, pitch, _) = q.to_euler_angles();
+
+  assert!((pitch - PI / 2.0).abs() < EPSILON);
+}
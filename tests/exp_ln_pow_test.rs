@@ -0,0 +1,53 @@
+extern crate quaternions;
+
+use quaternions::Quaternion;
+
+/// Fudge factor for float equality checks
+static EPSILON: f32 = 1e-5;
+
+#[test]
+fn test_ln_of_pure_rotation_has_zero_real_part() {
+  let half = std::f32::consts::FRAC_PI_4;
+  let q = Quaternion::new(half.cos(), 0.0, 0.0, half.sin());
+
+  let ln_q = q.ln();
+  assert!(ln_q.w.abs() < EPSILON);
+  assert!((ln_q.z - half).abs() < EPSILON);
+}
+
+#[test]
+fn test_exp_ln_round_trip() {
+  let q = Quaternion::new(0.5_f32, 0.2, -0.3, 0.1);
+
+  let round_tripped = q.ln().exp();
+  assert!((round_tripped.w - q.w).abs() < EPSILON);
+  assert!((round_tripped.x - q.x).abs() < EPSILON);
+  assert!((round_tripped.y - q.y).abs() < EPSILON);
+  assert!((round_tripped.z - q.z).abs() < EPSILON);
+}
+
+#[test]
+fn test_exp_underflow_branch_is_real() {
+  // `v_norm` underflows towards zero here, exercising the limit branch of `exp`.
+  let q = Quaternion::new(0.5_f32, 0.0, 0.0, 0.0);
+
+  let result = q.exp();
+  assert!((result.w - 0.5_f32.exp()).abs() < EPSILON);
+  assert!(result.x.abs() < EPSILON);
+  assert!(result.y.abs() < EPSILON);
+  assert!(result.z.abs() < EPSILON);
+}
+
+#[test]
+fn test_pow_half_halves_the_rotation_angle() {
+  // A 90 degree rotation about Z, represented with half-angle 45 degrees.
+  let half = std::f32::consts::FRAC_PI_4;
+  let q = Quaternion::new(half.cos(), 0.0, 0.0, half.sin());
+
+  let halved = q.pow(0.5);
+
+  // The half-power should be the 45 degree rotation about Z, i.e. half-angle 22.5 degrees.
+  let quarter = std::f32::consts::FRAC_PI_8;
+  assert!((halved.w - quarter.cos()).abs() < EPSILON);
+  assert!((halved.z - quarter.sin()).abs() < EPSILON);
+}
@@ -0,0 +1,39 @@
+extern crate quaternions;
+
+use quaternions::Quaternion;
+
+/// Fudge factor for float equality checks
+static EPSILON: f32 = 1e-5;
+
+fn assert_vec3_close(a: [f32; 3], b: [f32; 3]) {
+  for i in 0..3 {
+    assert!((a[i] - b[i]).abs() < EPSILON, "vectors differ at index {}: {} vs {}", i, a[i], b[i]);
+  }
+}
+
+#[test]
+fn test_rotate_vector_quarter_turn_about_z() {
+  let half = std::f32::consts::FRAC_PI_4;
+  let q = Quaternion::new(half.cos(), 0.0, 0.0, half.sin());
+
+  assert_vec3_close(q.rotate_vector([1.0, 0.0, 0.0]), [0.0, 1.0, 0.0]);
+}
+
+#[test]
+fn test_rotate_vector_identity_is_noop() {
+  let q = Quaternion::id();
+  assert_vec3_close(q.rotate_vector([1.0, 2.0, 3.0]), [1.0, 2.0, 3.0]);
+}
+
+#[test]
+fn test_rotate_vector_preserves_length() {
+  let half = std::f32::consts::FRAC_PI_4 / 2.0;
+  let q = Quaternion::new(half.cos(), half.sin(), half.sin(), 0.0);
+
+  let v = [3.0, -1.0, 2.0];
+  let rotated = q.rotate_vector(v);
+
+  let len_before = (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+  let len_after = (rotated[0] * rotated[0] + rotated[1] * rotated[1] + rotated[2] * rotated[2]).sqrt();
+  assert!((len_before - len_after).abs() < EPSILON);
+}
@@ -0,0 +1,44 @@
+extern crate quaternions;
+
+use quaternions::Quaternion;
+
+/// Fudge factor for float equality checks
+static EPSILON: f32 = 1e-5;
+
+#[test]
+fn test_rotation_between_rotates_from_onto_to() {
+  let from = [1.0_f32, 0.0, 0.0];
+  let to = [0.0_f32, 1.0, 0.0];
+
+  let q = Quaternion::rotation_between(from, to);
+  let rotated = q.rotate_vector(from);
+
+  assert!((rotated[0] - to[0]).abs() < EPSILON);
+  assert!((rotated[1] - to[1]).abs() < EPSILON);
+  assert!((rotated[2] - to[2]).abs() < EPSILON);
+}
+
+#[test]
+fn test_rotation_between_parallel_vectors_is_identity() {
+  let from = [1.0_f32, 2.0, 3.0];
+  let to = [2.0_f32, 4.0, 6.0];
+
+  let q = Quaternion::rotation_between(from, to);
+  assert!((q.w - 1.0).abs() < EPSILON);
+  assert!(q.x.abs() < EPSILON);
+  assert!(q.y.abs() < EPSILON);
+  assert!(q.z.abs() < EPSILON);
+}
+
+#[test]
+fn test_rotation_between_opposite_vectors_is_half_turn() {
+  let from = [1.0_f32, 0.0, 0.0];
+  let to = [-1.0_f32, 0.0, 0.0];
+
+  let q = Quaternion::rotation_between(from, to);
+  let rotated = q.rotate_vector(from);
+
+  assert!((rotated[0] - to[0]).abs() < EPSILON);
+  assert!((rotated[1] - to[1]).abs() < EPSILON);
+  assert!((rotated[2] - to[2]).abs() < EPSILON);
+}
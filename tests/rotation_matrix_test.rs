@@ -0,0 +1,79 @@
+extern crate quaternions;
+
+use quaternions::Quaternion;
+
+/// Fudge factor for float equality checks
+static EPSILON: f32 = 1e-5;
+
+fn assert_matrix3_close(a: [[f32; 3]; 3], b: [[f32; 3]; 3]) {
+  for row in 0..3 {
+    for col in 0..3 {
+      assert!(
+        (a[row][col] - b[row][col]).abs() < EPSILON,
+        "matrices differ at [{}][{}]: {} vs {}",
+        row,
+        col,
+        a[row][col],
+        b[row][col]
+      );
+    }
+  }
+}
+
+#[test]
+fn test_to_rotation_matrix3_quarter_turn_about_z() {
+  let half = std::f32::consts::FRAC_PI_4;
+  let q = Quaternion::new(half.cos(), 0.0, 0.0, half.sin());
+
+  let expected = [[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]];
+  assert_matrix3_close(q.to_rotation_matrix3(), expected);
+}
+
+#[test]
+fn test_to_rotation_matrix4_embeds_matrix3() {
+  let half = std::f32::consts::FRAC_PI_4;
+  let q = Quaternion::new(half.cos(), 0.0, 0.0, half.sin());
+
+  let m3 = q.to_rotation_matrix3();
+  let m4 = q.to_rotation_matrix4();
+
+  for row in 0..3 {
+    for col in 0..3 {
+      assert!((m4[row][col] - m3[row][col]).abs() < EPSILON);
+    }
+    assert!((m4[row][3]).abs() < EPSILON);
+  }
+  assert!((m4[3][0]).abs() < EPSILON);
+  assert!((m4[3][1]).abs() < EPSILON);
+  assert!((m4[3][2]).abs() < EPSILON);
+  assert!((m4[3][3] - 1.0).abs() < EPSILON);
+}
+
+#[test]
+fn test_rotation_matrix3_round_trip() {
+  let half = std::f32::consts::FRAC_PI_4;
+  let q = Quaternion::new(half.cos(), 0.0, 0.0, half.sin());
+
+  let m = q.to_rotation_matrix3();
+  let round_tripped = Quaternion::from_rotation_matrix3(&m);
+
+  assert!((round_tripped.w - q.w).abs() < EPSILON);
+  assert!((round_tripped.x - q.x).abs() < EPSILON);
+  assert!((round_tripped.y - q.y).abs() < EPSILON);
+  assert!((round_tripped.z - q.z).abs() < EPSILON);
+}
+
+#[test]
+fn test_rotation_matrix3_round_trip_near_pole() {
+  // Exercises the non-dominant-trace branches of `from_rotation_matrix3`: a 180 degree
+  // turn about X makes `trace <= 0` and `m00` the largest diagonal entry.
+  let q: Quaternion<f32> = Quaternion::new(0.0, 1.0, 0.0, 0.0);
+
+  let m = q.to_rotation_matrix3();
+  let round_tripped = Quaternion::from_rotation_matrix3(&m);
+
+  // The matrix doesn't distinguish a quaternion from its negation, so compare via dot
+  // product instead of component-wise equality.
+  let dot = round_tripped.w * q.w + round_tripped.x * q.x + round_tripped.y * q.y + round_tripped.z * q.z;
+  assert!(dot.abs() > 1.0 - EPSILON);
+}
@@ -0,0 +1,58 @@
+extern crate quaternions;
+
+use quaternions::{q, qi};
+
+/// Fudge factor for float equality checks
+static EPSILON: f32 = 1e-5;
+
+#[test]
+fn test_slerp_endpoints() {
+  let a = qi::<f32>(1, 0, 0, 0);
+  let b = q::<f32>(0.0, 1.0, 0.0, 0.0);
+
+  let at_start = a.slerp(&b, 0.0);
+  assert!((at_start.w - a.w).abs() < EPSILON);
+  assert!((at_start.x - a.x).abs() < EPSILON);
+
+  let at_end = a.slerp(&b, 1.0);
+  assert!((at_end.w - b.w).abs() < EPSILON);
+  assert!((at_end.x - b.x).abs() < EPSILON);
+}
+
+#[test]
+fn test_slerp_stays_unit_length() {
+  let a = qi::<f32>(1, 0, 0, 0);
+  let b = q::<f32>(0.0, 1.0, 0.0, 0.0);
+
+  let mid = a.slerp(&b, 0.5);
+  assert!((mid.square_length() - 1.0).abs() < EPSILON);
+}
+
+#[test]
+fn test_slerp_takes_shorter_arc() {
+  // `b` is the antipodal (negated) representation of a small rotation near `a`; slerp
+  // should flip it so interpolation still takes the short way around.
+  let a = qi::<f32>(1, 0, 0, 0);
+  let b = q::<f32>(-0.99, -0.14, 0.0, 0.0);
+
+  let mid = a.slerp(&b, 0.5);
+  assert!(mid.dot(&a) > 0.0);
+}
+
+#[test]
+fn test_slerp_nearly_parallel_falls_back_to_nlerp() {
+  let a = qi::<f32>(1, 0, 0, 0);
+  let b = q::<f32>(1.0, 0.0001, 0.0, 0.0);
+
+  let result = a.slerp(&b, 0.5);
+  assert!((result.square_length() - 1.0).abs() < EPSILON);
+}
+
+#[test]
+fn test_nlerp_stays_unit_length() {
+  let a = qi::<f32>(1, 0, 0, 0);
+  let b = q::<f32>(0.0, 1.0, 0.0, 0.0);
+
+  let result = a.nlerp(&b, 0.25);
+  assert!((result.square_length() - 1.0).abs() < EPSILON);
+}
@@ -0,0 +1,39 @@
+extern crate quaternions;
+
+use quaternions::{q, Quaternion};
+
+/// Fudge factor for float equality checks
+static EPSILON: f32 = 1e-5;
+
+#[test]
+fn test_normalize_produces_unit_length() {
+  let a = q::<f32>(1.0, 2.0, 3.0, 4.0);
+  let normalized = a.normalize();
+
+  assert!((normalized.square_length() - 1.0).abs() < EPSILON);
+  assert!(normalized.is_normalized(EPSILON));
+}
+
+#[test]
+fn test_is_normalized() {
+  assert!(Quaternion::id().is_normalized(EPSILON));
+  assert!(!q::<f32>(1.0, 2.0, 3.0, 4.0).is_normalized(EPSILON));
+}
+
+#[test]
+fn test_unit_quaternion_new_normalizes() {
+  let unit = quaternions::UnitQuaternion::new(q::<f32>(2.0, 0.0, 0.0, 0.0));
+  assert!((unit.square_length() - 1.0).abs() < EPSILON);
+  assert!((unit.w - 1.0).abs() < EPSILON);
+}
+
+#[test]
+fn test_unit_quaternion_inverse_is_conjugate() {
+  let unit = quaternions::UnitQuaternion::new(q::<f32>(1.0, 2.0, 3.0, 4.0));
+  let inverse = unit.inverse();
+
+  assert!((inverse.w - unit.w).abs() < EPSILON);
+  assert!((inverse.x + unit.x).abs() < EPSILON);
+  assert!((inverse.y + unit.y).abs() < EPSILON);
+  assert!((inverse.z + unit.z).abs() < EPSILON);
+}